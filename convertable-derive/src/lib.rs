@@ -0,0 +1,347 @@
+//! Derive macros for `convertable`.
+//!
+//! `#[derive(ConvertFrom)]` / `#[derive(TryConvertFrom)]` generate the
+//! `impl ConvertFrom<Src> for Self` / `impl TryConvertFrom<Src> for Self`
+//! bodies that would otherwise be hand-written field by field, the way the
+//! `convert_container` test in `convertable` does it manually.
+//!
+//! `#[derive(TryConvertFrom)]` always requires `#[convert(error = E)]`: the
+//! derive only ever sees `Self`'s fields, not `Src`'s (which may live in
+//! another crate), so it has no way to name the `Error` type that a given
+//! field's `try_convert_into()` call would produce.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parse_macro_input, Data, DeriveInput, Error, Expr, Fields, Ident, Index, Path, Result,
+    Type, Variant,
+};
+
+#[proc_macro_derive(ConvertFrom, attributes(convert))]
+pub fn derive_convert_from(input: TokenStream) -> TokenStream {
+    expand(input, Mode::Infallible)
+}
+
+#[proc_macro_derive(TryConvertFrom, attributes(convert))]
+pub fn derive_try_convert_from(input: TokenStream) -> TokenStream {
+    expand(input, Mode::Fallible)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Infallible,
+    Fallible,
+}
+
+fn expand(input: TokenStream, mode: Mode) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_impl(input, mode)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+struct ContainerAttr {
+    from: Path,
+    error: Option<Type>,
+}
+
+fn parse_container_attr(attrs: &[syn::Attribute]) -> Result<ContainerAttr> {
+    let mut from = None;
+    let mut error = None;
+    for attr in attrs {
+        if !attr.path().is_ident("convert") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("from") {
+                from = Some(meta.value()?.parse::<Path>()?);
+                Ok(())
+            } else if meta.path.is_ident("error") {
+                error = Some(meta.value()?.parse::<Type>()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `from` or `error`"))
+            }
+        })?;
+    }
+    let from = from.ok_or_else(|| {
+        Error::new(
+            Span::call_site(),
+            "`#[derive(ConvertFrom)]`/`#[derive(TryConvertFrom)]` requires `#[convert(from = path::to::Src)]`",
+        )
+    })?;
+    Ok(ContainerAttr { from, error })
+}
+
+#[derive(Default)]
+struct FieldAttr {
+    rename: Option<Ident>,
+    with: Option<Expr>,
+    default: bool,
+}
+
+fn parse_field_attr(attrs: &[syn::Attribute]) -> Result<FieldAttr> {
+    let mut field = FieldAttr::default();
+    for attr in attrs {
+        if !attr.path().is_ident("convert") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                field.rename = Some(meta.value()?.parse::<Ident>()?);
+                Ok(())
+            } else if meta.path.is_ident("with") {
+                field.with = Some(meta.value()?.parse::<Expr>()?);
+                Ok(())
+            } else if meta.path.is_ident("default") {
+                field.default = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `rename`, `with` or `default`"))
+            }
+        })?;
+    }
+    Ok(field)
+}
+
+/// The expression that reads the matching field out of `value`, and the
+/// field it is assigned to on `dest`.
+struct FieldPlan {
+    dest: TokenStream2,
+    value: TokenStream2,
+}
+
+fn field_plan(
+    mode: Mode,
+    dest_field: TokenStream2,
+    src_field: TokenStream2,
+    attr: &FieldAttr,
+) -> Result<FieldPlan> {
+    if attr.default && attr.with.is_some() {
+        return Err(Error::new(
+            Span::call_site(),
+            "`#[convert(default)]` and `#[convert(with = ...)]` are mutually exclusive",
+        ));
+    }
+    let value = if attr.default {
+        quote!(::core::default::Default::default())
+    } else if let Some(with) = &attr.with {
+        match mode {
+            Mode::Infallible => quote!((#with)(value.#src_field)),
+            Mode::Fallible => quote!((#with)(value.#src_field)?),
+        }
+    } else {
+        match mode {
+            Mode::Infallible => quote!(::convertable::ConvertInto::convert_into(value.#src_field)),
+            Mode::Fallible => {
+                quote!(::convertable::TryConvertInto::try_convert_into(value.#src_field)?)
+            }
+        }
+    };
+    Ok(FieldPlan {
+        dest: dest_field,
+        value,
+    })
+}
+
+fn struct_fields(mode: Mode, fields: &Fields) -> Result<Vec<FieldPlan>> {
+    let mut plans = Vec::new();
+    match fields {
+        Fields::Named(named) => {
+            for field in &named.named {
+                let attr = parse_field_attr(&field.attrs)?;
+                let ident = field.ident.as_ref().expect("named field has an ident");
+                let src_ident = attr.rename.clone().unwrap_or_else(|| ident.clone());
+                plans.push(field_plan(mode, quote!(#ident), quote!(#src_ident), &attr)?);
+            }
+        }
+        Fields::Unnamed(unnamed) => {
+            for (i, field) in unnamed.unnamed.iter().enumerate() {
+                let attr = parse_field_attr(&field.attrs)?;
+                if attr.rename.is_some() {
+                    return Err(Error::new(
+                        Span::call_site(),
+                        "`#[convert(rename = ...)]` is only supported on named fields",
+                    ));
+                }
+                let index = Index::from(i);
+                plans.push(field_plan(mode, quote!(#index), quote!(#index), &attr)?);
+            }
+        }
+        Fields::Unit => {}
+    }
+    Ok(plans)
+}
+
+fn struct_body(dest: &Ident, fields: &Fields, plans: &[FieldPlan]) -> TokenStream2 {
+    match fields {
+        Fields::Named(_) => {
+            let assigns = plans.iter().map(|p| {
+                let FieldPlan { dest, value } = p;
+                quote!(#dest: #value)
+            });
+            quote!(#dest { #(#assigns),* })
+        }
+        Fields::Unnamed(_) => {
+            let values = plans.iter().map(|p| &p.value);
+            quote!(#dest(#(#values),*))
+        }
+        Fields::Unit => quote!(#dest),
+    }
+}
+
+/// Like `field_plan`, but for a variant field bound directly out of a match
+/// pattern (`field` or `_`) rather than read off `value.field`.
+///
+/// `pattern` overrides the binding the match arm uses to destructure this
+/// field; `None` means bind it under `binding` as usual. `#[convert(default)]`
+/// ignores the source field entirely, so it rebinds to `_` to avoid an
+/// unused-variable warning on the generated arm.
+struct VariantFieldPlan {
+    pattern: Option<TokenStream2>,
+    value: TokenStream2,
+}
+
+fn variant_field_plan(mode: Mode, binding: &Ident, attr: &FieldAttr) -> Result<VariantFieldPlan> {
+    if attr.default && attr.with.is_some() {
+        return Err(Error::new(
+            Span::call_site(),
+            "`#[convert(default)]` and `#[convert(with = ...)]` are mutually exclusive",
+        ));
+    }
+    if attr.default {
+        return Ok(VariantFieldPlan {
+            pattern: Some(quote!(_)),
+            value: quote!(::core::default::Default::default()),
+        });
+    }
+    let value = if let Some(with) = &attr.with {
+        match mode {
+            Mode::Infallible => quote!((#with)(#binding)),
+            Mode::Fallible => quote!((#with)(#binding)?),
+        }
+    } else {
+        match mode {
+            Mode::Infallible => quote!(::convertable::ConvertInto::convert_into(#binding)),
+            Mode::Fallible => quote!(::convertable::TryConvertInto::try_convert_into(#binding)?),
+        }
+    };
+    Ok(VariantFieldPlan {
+        pattern: None,
+        value,
+    })
+}
+
+fn variant_arm(mode: Mode, src: &Path, dest: &Ident, variant: &Variant) -> Result<TokenStream2> {
+    let variant_ident = &variant.ident;
+    match &variant.fields {
+        Fields::Named(named) => {
+            let mut patterns = Vec::new();
+            let mut values = Vec::new();
+            for field in &named.named {
+                let attr = parse_field_attr(&field.attrs)?;
+                let ident = field.ident.as_ref().expect("named field has an ident");
+                let src_ident = attr.rename.clone().unwrap_or_else(|| ident.clone());
+                let plan = variant_field_plan(mode, ident, &attr)?;
+                match &plan.pattern {
+                    Some(pattern) => patterns.push(quote!(#src_ident: #pattern)),
+                    None if attr.rename.is_some() => patterns.push(quote!(#src_ident: #ident)),
+                    None => patterns.push(quote!(#ident)),
+                }
+                let value = &plan.value;
+                values.push(quote!(#ident: #value));
+            }
+            Ok(quote! {
+                #src::#variant_ident { #(#patterns),* } => #dest::#variant_ident { #(#values),* },
+            })
+        }
+        Fields::Unnamed(unnamed) => {
+            let mut patterns = Vec::new();
+            let mut values = Vec::new();
+            for (i, field) in unnamed.unnamed.iter().enumerate() {
+                let attr = parse_field_attr(&field.attrs)?;
+                if attr.rename.is_some() {
+                    return Err(Error::new(
+                        Span::call_site(),
+                        "`#[convert(rename = ...)]` is only supported on named fields",
+                    ));
+                }
+                let binding = Ident::new(&format!("field{i}"), Span::call_site());
+                let plan = variant_field_plan(mode, &binding, &attr)?;
+                patterns.push(plan.pattern.unwrap_or_else(|| quote!(#binding)));
+                values.push(plan.value);
+            }
+            Ok(quote! {
+                #src::#variant_ident(#(#patterns),*) => #dest::#variant_ident(#(#values),*),
+            })
+        }
+        Fields::Unit => Ok(quote! {
+            #src::#variant_ident => #dest::#variant_ident,
+        }),
+    }
+}
+
+fn expand_impl(input: DeriveInput, mode: Mode) -> Result<TokenStream2> {
+    let container = parse_container_attr(&input.attrs)?;
+    let dest = &input.ident;
+    let src = &container.from;
+
+    let body: TokenStream2 = match &input.data {
+        Data::Struct(data) => {
+            let plans = struct_fields(mode, &data.fields)?;
+            struct_body(dest, &data.fields, &plans)
+        }
+        Data::Enum(data) => {
+            let arms = data
+                .variants
+                .iter()
+                .map(|variant| variant_arm(mode, src, dest, variant))
+                .collect::<Result<Vec<_>>>()?;
+            quote! {
+                match value {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "`ConvertFrom`/`TryConvertFrom` cannot be derived for unions",
+            ))
+        }
+    };
+
+    Ok(match mode {
+        Mode::Infallible => quote! {
+            impl ::convertable::ConvertFrom<#src> for #dest {
+                fn convert_from(value: #src) -> Self {
+                    #body
+                }
+            }
+        },
+        Mode::Fallible => {
+            // Every field is converted with `value.field.try_convert_into()?`,
+            // whose `Error` type is `<FieldTy as TryConvertFrom<SrcFieldTy>>::Error`.
+            // We never parse `Src`'s definition (it may live in another crate),
+            // so we have no way to name `SrcFieldTy` and infer that type for the
+            // user; `#[convert(error = E)]` must be given explicitly instead.
+            let error = container.error.ok_or_else(|| {
+                Error::new(
+                    Span::call_site(),
+                    "`#[derive(TryConvertFrom)]` requires `#[convert(error = E)]`: \
+                     the derive can't see `Src`'s field types to infer it for you",
+                )
+            })?;
+            quote! {
+                impl ::convertable::TryConvertFrom<#src> for #dest {
+                    type Error = #error;
+
+                    fn try_convert_from(value: #src) -> ::core::result::Result<Self, Self::Error> {
+                        ::core::result::Result::Ok(#body)
+                    }
+                }
+            }
+        }
+    })
+}
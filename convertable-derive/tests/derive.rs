@@ -0,0 +1,157 @@
+use convertable::{ConvertFrom, ConvertInto, TryConvertFrom, TryConvertInto};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct Num(u8);
+
+convertable::convert_identity!(Num);
+convertable::try_convert_via_convert_into!(Num => Num);
+
+mod src {
+    use super::Num;
+
+    #[derive(Debug, PartialEq)]
+    pub struct Point {
+        pub x: Num,
+        pub y: Num,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct Named {
+        pub first: Num,
+        pub second: Num,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct Tuple(pub Num, pub Num);
+
+    #[derive(Debug, PartialEq)]
+    pub enum Shape {
+        Circle { radius: Num },
+        Square(Num),
+        Unit,
+    }
+}
+
+#[derive(Debug, PartialEq, ConvertFrom)]
+#[convert(from = src::Point)]
+struct Point {
+    x: Num,
+    y: Num,
+}
+
+#[test]
+fn derives_convert_from_for_struct() {
+    let point: Point = src::Point {
+        x: Num(1),
+        y: Num(2),
+    }
+    .convert_into();
+    assert_eq!(
+        point,
+        Point {
+            x: Num(1),
+            y: Num(2)
+        }
+    );
+}
+
+#[derive(Debug, PartialEq, ConvertFrom)]
+#[convert(from = src::Named)]
+struct Renamed {
+    #[convert(rename = first)]
+    a: Num,
+    #[convert(rename = second)]
+    b: Num,
+}
+
+#[test]
+fn derives_convert_from_with_rename() {
+    let renamed: Renamed = src::Named {
+        first: Num(1),
+        second: Num(2),
+    }
+    .convert_into();
+    assert_eq!(
+        renamed,
+        Renamed {
+            a: Num(1),
+            b: Num(2)
+        }
+    );
+}
+
+#[derive(Debug, PartialEq, ConvertFrom)]
+#[convert(from = src::Tuple)]
+struct ScaledTuple(Num, #[convert(with = |v: Num| Num(v.0 * 10))] Num);
+
+#[test]
+fn derives_convert_from_with_with() {
+    let scaled: ScaledTuple = src::Tuple(Num(1), Num(2)).convert_into();
+    assert_eq!(scaled, ScaledTuple(Num(1), Num(20)));
+}
+
+#[derive(Debug, PartialEq, ConvertFrom)]
+#[convert(from = src::Tuple)]
+struct DefaultedTuple(Num, #[convert(default)] u8);
+
+#[test]
+fn derives_convert_from_with_default() {
+    let defaulted: DefaultedTuple = src::Tuple(Num(1), Num(2)).convert_into();
+    assert_eq!(defaulted, DefaultedTuple(Num(1), 0));
+}
+
+#[derive(Debug, PartialEq, TryConvertFrom)]
+#[convert(from = src::Tuple, error = core::convert::Infallible)]
+struct TryPoint(Num, Num);
+
+#[test]
+fn derives_try_convert_from_with_explicit_error() {
+    let point: Result<TryPoint, core::convert::Infallible> =
+        src::Tuple(Num(1), Num(2)).try_convert_into();
+    assert_eq!(point, Ok(TryPoint(Num(1), Num(2))));
+}
+
+#[derive(Debug, PartialEq, ConvertFrom)]
+#[convert(from = src::Shape)]
+enum Shape {
+    Circle { radius: Num },
+    Square(Num),
+    Unit,
+}
+
+#[test]
+fn derives_convert_from_for_enum() {
+    let circle: Shape = src::Shape::Circle { radius: Num(3) }.convert_into();
+    assert_eq!(circle, Shape::Circle { radius: Num(3) });
+
+    let square: Shape = src::Shape::Square(Num(4)).convert_into();
+    assert_eq!(square, Shape::Square(Num(4)));
+
+    let unit: Shape = src::Shape::Unit.convert_into();
+    assert_eq!(unit, Shape::Unit);
+}
+
+#[derive(Debug, PartialEq, ConvertFrom)]
+#[convert(from = src::Shape)]
+enum AdjustedShape {
+    Circle {
+        #[convert(rename = radius, with = |v: Num| Num(v.0 * 2))]
+        doubled_radius: Num,
+    },
+    Square(#[convert(default)] Num),
+    Unit,
+}
+
+#[test]
+fn derives_convert_from_for_enum_with_variant_field_attrs() {
+    let circle: AdjustedShape = src::Shape::Circle { radius: Num(3) }.convert_into();
+    assert_eq!(
+        circle,
+        AdjustedShape::Circle {
+            doubled_radius: Num(6)
+        }
+    );
+
+    let square: AdjustedShape = src::Shape::Square(Num(4)).convert_into();
+    assert_eq!(square, AdjustedShape::Square(Num(0)));
+}
@@ -1,6 +1,28 @@
 #![no_std]
-
-use core::convert::Infallible;
+//! Conversion traits mirroring std's `From`/`Into`/`AsRef`/`AsMut`.
+//!
+//! ## Known limitation: no true reflexive blanket impls
+//!
+//! Unlike std's `impl<T> From<T> for T`, this crate cannot offer a blanket
+//! `impl<T> ConvertFrom<T> for T` (or `ConvertAsRef`/`ConvertAsMut`
+//! forwarding for arbitrary `&U: ConvertAsRef<T>`). Coherence checking only
+//! unifies impl *heads*, ignoring where-clauses, so a reflexive
+//! `ConvertFrom` blanket would overlap the tuple/array/`Option`/`Result`
+//! impls below at, e.g., `T = (A, B)` (unifying both impls' generic
+//! parameters with `(A, B)` itself), and the forwarding `ConvertAsRef`
+//! blanket would overlap the reflexive `ConvertAsRef<T> for T` impl at
+//! `T = &U` — the same way `impl<T, U: Into<V>> ConvertFrom<U> for V` would
+//! overlap every hand-written impl. Gating the reflexive impl behind an associated-type
+//! bound (e.g. `where T: TryConvertFrom<T, Error = Infallible>`) doesn't
+//! avoid this, since coherence doesn't look at where-clauses at all; nor
+//! does a sealed marker trait, since the overlapping impl *heads* are what
+//! conflict, not their trait bounds. Stable Rust has no specialization to
+//! fall back on.
+//!
+//! `convert_identity!`/`convert_identity` below are the opt-in workaround:
+//! register one concrete type at a time instead of every type at once.
+#[cfg(feature = "derive")]
+pub use convertable_derive::{ConvertFrom, TryConvertFrom};
 
 pub trait ConvertFrom<T>: Sized {
     #[must_use]
@@ -24,6 +46,54 @@ pub trait TryConvertInto<T>: Sized {
     fn try_convert_into(self) -> Result<T, Self::Error>;
 }
 
+pub trait ConvertAsRef<T> {
+    fn convert_as_ref(&self) -> &T;
+}
+
+pub trait ConvertAsMut<T> {
+    fn convert_as_mut(&mut self) -> &mut T;
+}
+
+// ConvertAsRef/ConvertAsMut are reflexive
+impl<T> ConvertAsRef<T> for T {
+    fn convert_as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T> ConvertAsMut<T> for T {
+    fn convert_as_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+// ConvertAsRef/ConvertAsMut forward through references. These are stated
+// directly in terms of `T` (the referent) rather than the originally
+// requested `impl<T, U: ConvertAsRef<T>> ConvertAsRef<T> for &U`: seeing the
+// module-level "Known limitation" note above, that forwarding impl overlaps
+// the reflexive impls above at `T = &T0` (set `U = T0` in the forwarding
+// impl, so both impls claim `ConvertAsRef<&T0> for &T0`), so it's not
+// available on stable Rust. This narrower form still covers `&U`/`&mut U`
+// borrowing to their own referent, just not forwarding further through a
+// user's own `ConvertAsRef` impl on `U` itself.
+impl<T> ConvertAsRef<T> for &T {
+    fn convert_as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T> ConvertAsRef<T> for &mut T {
+    fn convert_as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T> ConvertAsMut<T> for &mut T {
+    fn convert_as_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
 // ConvertFrom implies ConvertInto
 impl<T, U> ConvertInto<U> for T
 where
@@ -46,29 +116,257 @@ where
     }
 }
 
-// ConvertInto implies TryConvertFrom
-impl<T, U> TryConvertFrom<U> for T
+/// Opts a `Src => Dest` pair into `TryConvertFrom` by forwarding to an
+/// existing `ConvertInto` impl, with `Error = Infallible`. Accepts a
+/// comma-separated list of pairs.
+///
+/// A blanket `impl<T, U: ConvertInto<T>> TryConvertFrom<U> for T` would
+/// overlap with any concrete `TryConvertFrom` impl on `T` (e.g. the
+/// tuple/array/`Option`/`Result` impls below), the same way a blanket
+/// `impl<T, U: Into<V>> ConvertFrom<U> for V` would overlap hand-written
+/// `ConvertFrom` impls, so this opts in one pair at a time instead.
+#[macro_export]
+macro_rules! try_convert_via_convert_into {
+    ($($src:ty => $dest:ty),+ $(,)?) => {
+        $(
+            impl $crate::TryConvertFrom<$src> for $dest {
+                type Error = ::core::convert::Infallible;
+
+                fn try_convert_from(value: $src) -> ::core::result::Result<Self, Self::Error> {
+                    ::core::result::Result::Ok($crate::ConvertInto::convert_into(value))
+                }
+            }
+        )+
+    };
+}
+
+/// Opts a `Src => Dest` pair into `ConvertFrom` by forwarding to an existing
+/// `Into` impl. Accepts a comma-separated list of pairs.
+///
+/// A blanket `impl<T, U: Into<V>> ConvertFrom<U> for V` would overlap with
+/// every hand-written `ConvertFrom` impl, so this macro opts in one pair at a
+/// time instead.
+#[macro_export]
+macro_rules! convert_via_std {
+    ($($src:ty => $dest:ty),+ $(,)?) => {
+        $(
+            impl $crate::ConvertFrom<$src> for $dest {
+                fn convert_from(value: $src) -> Self {
+                    ::core::convert::Into::into(value)
+                }
+            }
+        )+
+    };
+}
+
+/// Opts a `Src => Dest` pair into `TryConvertFrom` by forwarding to an
+/// existing `TryInto` impl, reusing `Src`'s `TryInto::Error`. Accepts a
+/// comma-separated list of pairs.
+#[macro_export]
+macro_rules! try_convert_via_std {
+    ($($src:ty => $dest:ty),+ $(,)?) => {
+        $(
+            impl $crate::TryConvertFrom<$src> for $dest {
+                type Error = <$src as ::core::convert::TryInto<$dest>>::Error;
+
+                fn try_convert_from(value: $src) -> ::core::result::Result<Self, Self::Error> {
+                    ::core::convert::TryInto::try_into(value)
+                }
+            }
+        )+
+    };
+}
+
+// Blanket element-wise impls for tuples (up to arity 12), arrays, `Option`
+// and `Result`, so containers of convertible types convert without a
+// hand-written impl per container.
+
+impl<T, U> ConvertFrom<Option<T>> for Option<U>
 where
-    U: ConvertInto<T>,
+    U: ConvertFrom<T>,
 {
-    type Error = Infallible;
+    fn convert_from(value: Option<T>) -> Self {
+        value.map(U::convert_from)
+    }
+}
+
+impl<T, U> TryConvertFrom<Option<T>> for Option<U>
+where
+    U: TryConvertFrom<T>,
+{
+    type Error = U::Error;
 
-    fn try_convert_from(value: U) -> Result<Self, Self::Error> {
-        Ok(U::convert_into(value))
+    fn try_convert_from(value: Option<T>) -> Result<Self, Self::Error> {
+        Ok(match value {
+            Some(value) => Some(U::try_convert_from(value)?),
+            None => None,
+        })
     }
 }
 
+impl<T, U, E, F> ConvertFrom<Result<T, E>> for Result<U, F>
+where
+    U: ConvertFrom<T>,
+    F: ConvertFrom<E>,
+{
+    fn convert_from(value: Result<T, E>) -> Self {
+        match value {
+            Ok(value) => Ok(U::convert_from(value)),
+            Err(error) => Err(F::convert_from(error)),
+        }
+    }
+}
+
+// The `Err` side is converted with `ConvertFrom` rather than
+// `TryConvertFrom`, mirroring how `?` converts error types infallibly via
+// `From`. Only the `Ok` side can fail, so its error is `Self::Error`.
+impl<T, U, E, F> TryConvertFrom<Result<T, E>> for Result<U, F>
+where
+    U: TryConvertFrom<T>,
+    F: ConvertFrom<E>,
+{
+    type Error = U::Error;
+
+    fn try_convert_from(value: Result<T, E>) -> Result<Self, Self::Error> {
+        Ok(match value {
+            Ok(value) => Ok(U::try_convert_from(value)?),
+            Err(error) => Err(F::convert_from(error)),
+        })
+    }
+}
+
+impl<T, U, const N: usize> ConvertFrom<[T; N]> for [U; N]
+where
+    U: ConvertFrom<T>,
+{
+    fn convert_from(value: [T; N]) -> Self {
+        value.map(U::convert_from)
+    }
+}
+
+// `[T; N]` has no stable fallible `map`, so elements are converted in place
+// into a `[MaybeUninit<U>; N]`, dropping whatever already converted if a
+// later element fails, to avoid leaking or reading uninitialized memory.
+impl<T, U, const N: usize> TryConvertFrom<[T; N]> for [U; N]
+where
+    U: TryConvertFrom<T>,
+{
+    type Error = U::Error;
+
+    fn try_convert_from(value: [T; N]) -> Result<Self, Self::Error> {
+        use core::mem::MaybeUninit;
+
+        let mut out: [MaybeUninit<U>; N] = unsafe { MaybeUninit::uninit().assume_init() };
+        for (index, item) in value.into_iter().enumerate() {
+            match U::try_convert_from(item) {
+                Ok(converted) => {
+                    out[index].write(converted);
+                }
+                Err(error) => {
+                    for slot in &mut out[..index] {
+                        unsafe { slot.assume_init_drop() };
+                    }
+                    return Err(error);
+                }
+            }
+        }
+        Ok(out.map(|slot| unsafe { slot.assume_init() }))
+    }
+}
+
+// Tuples share one error type across all elements (a simpler first cut than
+// an enum keyed by element position); mixing element error types requires a
+// hand-written impl.
+macro_rules! tuple_convert_impls {
+    ($first_src:ident, $first_dest:ident $(; $src:ident, $dest:ident)* $(,)?) => {
+        impl<$first_src, $first_dest, $($src, $dest),*> ConvertFrom<($first_src, $($src),*)>
+            for ($first_dest, $($dest),*)
+        where
+            $first_dest: ConvertFrom<$first_src>,
+            $($dest: ConvertFrom<$src>,)*
+        {
+            #[allow(non_snake_case)]
+            fn convert_from(value: ($first_src, $($src),*)) -> Self {
+                let ($first_src, $($src),*) = value;
+                ($first_dest::convert_from($first_src), $($dest::convert_from($src)),*)
+            }
+        }
+
+        impl<$first_src, $first_dest, $($src, $dest),*> TryConvertFrom<($first_src, $($src),*)>
+            for ($first_dest, $($dest),*)
+        where
+            $first_dest: TryConvertFrom<$first_src>,
+            $($dest: TryConvertFrom<$src, Error = <$first_dest as TryConvertFrom<$first_src>>::Error>,)*
+        {
+            type Error = <$first_dest as TryConvertFrom<$first_src>>::Error;
+
+            #[allow(non_snake_case)]
+            fn try_convert_from(value: ($first_src, $($src),*)) -> Result<Self, Self::Error> {
+                let ($first_src, $($src),*) = value;
+                Ok((
+                    $first_dest::try_convert_from($first_src)?,
+                    $($dest::try_convert_from($src)?),*
+                ))
+            }
+        }
+    };
+}
+
+tuple_convert_impls!(A1, B1);
+tuple_convert_impls!(A1, B1; A2, B2);
+tuple_convert_impls!(A1, B1; A2, B2; A3, B3);
+tuple_convert_impls!(A1, B1; A2, B2; A3, B3; A4, B4);
+tuple_convert_impls!(A1, B1; A2, B2; A3, B3; A4, B4; A5, B5);
+tuple_convert_impls!(A1, B1; A2, B2; A3, B3; A4, B4; A5, B5; A6, B6);
+tuple_convert_impls!(A1, B1; A2, B2; A3, B3; A4, B4; A5, B5; A6, B6; A7, B7);
+tuple_convert_impls!(A1, B1; A2, B2; A3, B3; A4, B4; A5, B5; A6, B6; A7, B7; A8, B8);
+tuple_convert_impls!(A1, B1; A2, B2; A3, B3; A4, B4; A5, B5; A6, B6; A7, B7; A8, B8; A9, B9);
+tuple_convert_impls!(A1, B1; A2, B2; A3, B3; A4, B4; A5, B5; A6, B6; A7, B7; A8, B8; A9, B9; A10, B10);
+tuple_convert_impls!(A1, B1; A2, B2; A3, B3; A4, B4; A5, B5; A6, B6; A7, B7; A8, B8; A9, B9; A10, B10; A11, B11);
+tuple_convert_impls!(A1, B1; A2, B2; A3, B3; A4, B4; A5, B5; A6, B6; A7, B7; A8, B8; A9, B9; A10, B10; A11, B11; A12, B12);
+
+/// `core::convert::identity`'s counterpart: returns `x` unchanged.
+#[must_use]
+pub const fn convert_identity<T>(x: T) -> T {
+    x
+}
+
+/// Opts a type into a reflexive `ConvertFrom<T> for T`, so
+/// `x.convert_into::<T>()` is available for `T` without a hand-written impl.
+/// Accepts a comma-separated list of types.
+///
+/// There's no blanket form of this — see the "Known limitation" note at the
+/// top of this crate for why coherence rules it out on stable Rust.
+#[macro_export]
+macro_rules! convert_identity {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl $crate::ConvertFrom<$ty> for $ty {
+                fn convert_from(value: $ty) -> Self {
+                    value
+                }
+            }
+        )+
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // Not every test that expands this constructs `A`/`B`/`C` directly (some
+    // only go through `convert_into()`), so allow the resulting dead-code
+    // warnings here instead of peppering every call site.
     macro_rules! structs {
         () => {
             #[derive(Debug, PartialEq)]
+            #[allow(dead_code)]
             pub struct A(u8);
             #[derive(Debug, PartialEq)]
+            #[allow(dead_code)]
             pub struct B(u8);
             #[derive(Debug, PartialEq)]
+            #[allow(dead_code)]
             pub struct C<T>(T);
         };
     }
@@ -99,44 +397,92 @@ mod tests {
         assert_eq!(actual, Ok(B(1)));
     }
 
-    // #[test]
-    // fn into_implies_convert_from() {
-    //     structs!();
-    //     impl Into<B> for A {
-    //         fn into(self) -> B {
-    //             B(self.0)
-    //         }
-    //     }
-    //     assert_eq!(B::convert_from(A(1)), B(1));
-    // }
-
-    // #[test]
-    // fn try_into_implies_try_convert_from() {
-    //     structs!();
-    //     impl TryInto<B> for A {
-    //         type Error = ();
-    //         fn try_into(self) -> Result<B, Self::Error> {
-    //             Ok(B(self.0))
-    //         }
-    //     }
-    //     assert_eq!(B::try_convert_from(A(1)), Ok(B(1)));
-    // }
-
-    #[test]
-    fn convert_into_implies_try_convert_from() {
+    // A blanket `impl<T, U: Into<V>> ConvertFrom<U> for V` would overlap with
+    // every hand-written `ConvertFrom` impl, so `Into`/`TryInto` only feed
+    // `ConvertFrom`/`TryConvertFrom` when opted in via `convert_via_std!`.
+    #[test]
+    fn into_implies_convert_from_via_opt_in() {
+        structs!();
+        // Deliberately `Into`, not `From`: this test exercises the std-`Into`
+        // bridge specifically, not the `From` one.
+        #[allow(clippy::from_over_into)]
+        impl Into<B> for A {
+            fn into(self) -> B {
+                B(self.0)
+            }
+        }
+        convert_via_std!(A => B);
+        assert_eq!(B::convert_from(A(1)), B(1));
+    }
+
+    #[test]
+    fn try_into_implies_try_convert_from_via_opt_in() {
+        structs!();
+        impl TryInto<B> for A {
+            type Error = ();
+            fn try_into(self) -> Result<B, Self::Error> {
+                Ok(B(self.0))
+            }
+        }
+        try_convert_via_std!(A => B);
+        assert_eq!(B::try_convert_from(A(1)), Ok(B(1)));
+    }
+
+    #[test]
+    fn convert_into_implies_try_convert_from_via_opt_in() {
         structs!();
         impl ConvertInto<B> for A {
             fn convert_into(self) -> B {
                 B(self.0)
             }
         }
-        let actual: Result<B, Infallible> = B::try_convert_from(A(1));
+        try_convert_via_convert_into!(A => B);
+        let actual: Result<B, core::convert::Infallible> = B::try_convert_from(A(1));
         assert_eq!(actual, Ok(B(1)));
     }
 
+    #[test]
+    fn convert_as_ref_is_reflexive() {
+        structs!();
+        let a = A(1);
+        let actual: &A = a.convert_as_ref();
+        assert_eq!(actual, &A(1));
+    }
+
+    #[test]
+    fn convert_as_mut_is_reflexive() {
+        structs!();
+        let mut a = A(1);
+        let actual: &mut A = a.convert_as_mut();
+        actual.0 = 2;
+        assert_eq!(a, A(2));
+    }
+
+    #[test]
+    fn convert_as_ref_forwards_through_reference() {
+        structs!();
+        let a = A(1);
+        let r: &A = &a;
+        let actual: &A = r.convert_as_ref();
+        assert_eq!(actual, &A(1));
+    }
+
+    #[test]
+    fn convert_as_mut_forwards_through_mut_reference() {
+        structs!();
+        let mut a = A(1);
+        let r: &mut A = &mut a;
+        let actual: &mut A = r.convert_as_mut();
+        actual.0 = 2;
+        assert_eq!(a, A(2));
+    }
+
     #[test]
     fn convert_container() {
         structs!();
+        // Deliberately `Into`, not `From`: this test exercises a hand-written
+        // container impl bounded on std's `Into` specifically.
+        #[allow(clippy::from_over_into)]
         impl Into<B> for A {
             fn into(self) -> B {
                 B(self.0)
@@ -169,4 +515,151 @@ mod tests {
         let actual: Result<C<B>, ()> = C::try_convert_from(C(A(1)));
         assert_eq!(actual, Ok(C(B(1))));
     }
+
+    #[test]
+    fn convert_tuple() {
+        structs!();
+        impl ConvertFrom<A> for B {
+            fn convert_from(value: A) -> Self {
+                Self(value.0)
+            }
+        }
+        let actual: (B, B) = (A(1), A(2)).convert_into();
+        assert_eq!(actual, (B(1), B(2)));
+    }
+
+    #[test]
+    fn try_convert_tuple() {
+        structs!();
+        impl TryConvertFrom<A> for B {
+            type Error = ();
+            fn try_convert_from(value: A) -> Result<Self, Self::Error> {
+                Ok(Self(value.0))
+            }
+        }
+        let actual: Result<(B, B), ()> = (A(1), A(2)).try_convert_into();
+        assert_eq!(actual, Ok((B(1), B(2))));
+    }
+
+    #[test]
+    fn convert_array() {
+        structs!();
+        impl ConvertFrom<A> for B {
+            fn convert_from(value: A) -> Self {
+                Self(value.0)
+            }
+        }
+        let actual: [B; 2] = [A(1), A(2)].convert_into();
+        assert_eq!(actual, [B(1), B(2)]);
+    }
+
+    #[test]
+    fn try_convert_array_ok() {
+        structs!();
+        impl TryConvertFrom<A> for B {
+            type Error = ();
+            fn try_convert_from(value: A) -> Result<Self, Self::Error> {
+                Ok(Self(value.0))
+            }
+        }
+        let actual: Result<[B; 2], ()> = [A(1), A(2)].try_convert_into();
+        assert_eq!(actual, Ok([B(1), B(2)]));
+    }
+
+    #[test]
+    fn try_convert_array_err_drops_already_converted() {
+        struct Item(u8);
+
+        struct Tracked<'a>(&'a core::cell::Cell<u32>);
+        impl Drop for Tracked<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+        impl<'a> TryConvertFrom<(Item, &'a core::cell::Cell<u32>)> for Tracked<'a> {
+            type Error = ();
+            fn try_convert_from(
+                (item, counter): (Item, &'a core::cell::Cell<u32>),
+            ) -> Result<Self, Self::Error> {
+                if item.0 == 0 {
+                    Err(())
+                } else {
+                    Ok(Self(counter))
+                }
+            }
+        }
+
+        let dropped = core::cell::Cell::new(0u32);
+        let actual: Result<[Tracked; 2], ()> =
+            [(Item(1), &dropped), (Item(0), &dropped)].try_convert_into();
+        assert!(actual.is_err());
+        // The first element converted fine and was written into the
+        // partially-initialized array; `assume_init_drop` must run its
+        // destructor when the second element's error unwinds the attempt.
+        assert_eq!(dropped.get(), 1);
+    }
+
+    #[test]
+    fn convert_option() {
+        structs!();
+        impl ConvertFrom<A> for B {
+            fn convert_from(value: A) -> Self {
+                Self(value.0)
+            }
+        }
+        let some: Option<B> = Some(A(1)).convert_into();
+        let none: Option<B> = Option::<A>::None.convert_into();
+        assert_eq!(some, Some(B(1)));
+        assert_eq!(none, None);
+    }
+
+    #[test]
+    fn convert_result() {
+        structs!();
+        impl ConvertFrom<A> for B {
+            fn convert_from(value: A) -> Self {
+                Self(value.0)
+            }
+        }
+        let ok: Result<B, B> = Result::<A, A>::Ok(A(1)).convert_into();
+        let err: Result<B, B> = Result::<A, A>::Err(A(2)).convert_into();
+        assert_eq!(ok, Ok(B(1)));
+        assert_eq!(err, Err(B(2)));
+    }
+
+    #[test]
+    fn try_convert_result() {
+        structs!();
+        #[derive(Debug, PartialEq)]
+        struct D(u8);
+        impl TryConvertFrom<A> for B {
+            type Error = ();
+            fn try_convert_from(value: A) -> Result<Self, Self::Error> {
+                Ok(Self(value.0))
+            }
+        }
+        impl ConvertFrom<A> for D {
+            fn convert_from(value: A) -> Self {
+                Self(value.0)
+            }
+        }
+        let ok: Result<Result<B, D>, ()> = Result::<A, A>::Ok(A(1)).try_convert_into();
+        let err: Result<Result<B, D>, ()> = Result::<A, A>::Err(A(2)).try_convert_into();
+        assert_eq!(ok, Ok(Ok(B(1))));
+        assert_eq!(err, Ok(Err(D(2))));
+    }
+
+    #[test]
+    fn convert_identity_returns_input_unchanged() {
+        structs!();
+        assert_eq!(convert_identity(A(1)), A(1));
+    }
+
+    #[test]
+    fn convert_from_reflexive_via_opt_in() {
+        structs!();
+        convert_identity!(A);
+        let actual: A = A(1).convert_into();
+        assert_eq!(actual, A(1));
+    }
 }
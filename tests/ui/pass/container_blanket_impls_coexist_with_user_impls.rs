@@ -0,0 +1,43 @@
+// The blanket tuple/array/Option/Result impls only apply to those container
+// types, so a user is still free to hand-write `ConvertFrom`/`TryConvertFrom`
+// on their own types without conflicting with them.
+use convertable::{ConvertFrom, ConvertInto, TryConvertFrom, TryConvertInto};
+
+struct Celsius(f64);
+#[derive(Debug)]
+struct Fahrenheit(f64);
+
+impl ConvertFrom<Celsius> for Fahrenheit {
+    fn convert_from(value: Celsius) -> Self {
+        Fahrenheit(value.0 * 9.0 / 5.0 + 32.0)
+    }
+}
+
+impl TryConvertFrom<Celsius> for Fahrenheit {
+    type Error = ();
+
+    fn try_convert_from(value: Celsius) -> Result<Self, Self::Error> {
+        Ok(Fahrenheit(value.0 * 9.0 / 5.0 + 32.0))
+    }
+}
+
+fn main() {
+    let pair: (Fahrenheit, Fahrenheit) = (Celsius(0.0), Celsius(100.0)).convert_into();
+    assert_eq!(pair.0.0, 32.0);
+
+    let array: [Fahrenheit; 2] = [Celsius(0.0), Celsius(100.0)].convert_into();
+    assert_eq!(array[0].0, 32.0);
+
+    let option: Option<Fahrenheit> = Some(Celsius(0.0)).convert_into();
+    assert_eq!(option.unwrap().0, 32.0);
+
+    let result: Result<Fahrenheit, Fahrenheit> = Ok::<Celsius, Celsius>(Celsius(0.0)).convert_into();
+    assert_eq!(result.unwrap().0, 32.0);
+
+    let try_pair: Result<(Fahrenheit, Fahrenheit), ()> =
+        (Celsius(0.0), Celsius(100.0)).try_convert_into();
+    assert_eq!(try_pair.unwrap().0.0, 32.0);
+
+    let try_array: Result<[Fahrenheit; 2], ()> = [Celsius(0.0), Celsius(100.0)].try_convert_into();
+    assert_eq!(try_array.unwrap()[0].0, 32.0);
+}